@@ -3,11 +3,38 @@
 //! A compile-time check to ensure that a type uses uniform types across its fields.
 //!
 //! An example use for this macro is to ensure that a struct `#[repr(C)]` layout can
-//! be correctly mapped onto a slice of the (uniform) field type.
+//! be correctly mapped onto a slice of the (uniform) field type. To that end, the
+//! macro also generates `as_slice`, `as_mut_slice`, and `field_count` inherent methods
+//! that expose the struct as a `&[T]` / `&mut [T]` view over its uniform field type.
+//!
+//! Named-field structs, tuple structs, and unit structs are all supported. `enum`
+//! types are supported too: each variant's fields are checked for uniformity
+//! independently, since different variants are allowed to use different field types.
+//!
+//! A field can be excluded from the uniformity check and the slice mapping by
+//! marking it `#[uniform(skip)]`. Fields of type `PhantomData<..>` are excluded
+//! automatically, since they are zero-sized and carry no layout information.
+//!
+//! Because the whole point is reinterpreting the struct as a slice of the field
+//! type, that mapping is only sound with a `#[repr(C)]` or `#[repr(transparent)]`
+//! layout, so the macro requires one by default and reports a compile error
+//! otherwise. Pass `#[ensure_uniform_type(lenient)]` to only check field-type
+//! uniformity without requiring a `repr`.
 //!
 //! ## Note
 //!
-//! The type check is currently name-based.
+//! The type check is enforced by the compiler itself: the macro emits a hidden
+//! generic function that is only well-typed if all field types unify, so aliases,
+//! qualified paths, and re-exports of the same underlying type are handled correctly.
+//! This check does not depend on monomorphization, so it is enforced unconditionally.
+//!
+//! The `#[uniform(skip)]` zero-size check and the struct's size/align check are
+//! different: they live in an associated `const` on a (possibly generic) `impl`
+//! block, and Rust only evaluates such a const once it is actually referenced for a
+//! concrete type. For a non-generic struct this happens unconditionally, but for a
+//! generic struct the check is only forced once `field_count`, `as_slice`, or
+//! `as_mut_slice` is called (directly or transitively) for some concrete type
+//! parameter; an unused generic struct will not have these checks run at all.
 //!
 //! ## Examples
 //!
@@ -15,6 +42,7 @@
 //!
 //! ```compile_fail
 //! #[ensure_uniform_type::ensure_uniform_type]
+//! #[repr(C)]
 //! pub struct Example<T>
 //! {
 //!     /// First field
@@ -25,25 +53,22 @@
 //! }
 //! ```
 //!
-//! The above would fail to compile, instead giving the error:
+//! The above would fail to compile, instead giving a compiler-generated type
+//! mismatch error pointing at the offending field:
 //!
 //! ```plain
-//! error: Struct DifferentialDriveState has fields of different types. Expected uniform use of T, found u32 in field lol.
-//! --> src/differential_drive.rs:16:1
-//! |
-//! 16 | / /// A state of a differential drive robot, or differential wheeled robot.
-//! 18 | | #[ensure_uniform_type]
-//! 19 | | pub struct Example<T>
-//! ...  |
-//! 37 | |     offending: u32,
-//! 38 | | }
-//! | |_^
+//! error[E0308]: mismatched types
+//!  --> src/lib.rs:24:17
+//!   |
+//! 24|     offending: u32,
+//!   |                ^^^ expected `PhantomData<T>`, found `PhantomData<u32>`
 //! ```
 //!
 //! By contrast, the following would compile without an error:
 //!
 //! ```
 //! #[ensure_uniform_type::ensure_uniform_type]
+//! #[repr(C)]
 //! pub struct Example<T>
 //! {
 //!     x: T,
@@ -56,16 +81,31 @@
 extern crate proc_macro;
 use proc_macro::TokenStream;
 
-use quote::quote;
-use syn::{parse_macro_input, ItemStruct};
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Attribute, Fields, Item, ItemEnum, ItemStruct, Type};
 
 /// # Ensure uniform field types
 ///
 /// A compile-time check to ensure that a type uses uniform types across its fields.
 ///
+/// Named-field structs, tuple structs, and unit structs are all supported. For
+/// `enum` types, each variant is checked independently.
+///
 /// ## Note
 ///
-/// The type check is currently name-based.
+/// The type check is enforced by the compiler itself: the macro emits a hidden
+/// generic function that is only well-typed if all field types unify, so aliases,
+/// qualified paths, and re-exports of the same underlying type are handled correctly.
+/// This check does not depend on monomorphization, so it is enforced unconditionally.
+///
+/// The `#[uniform(skip)]` zero-size check and the struct's size/align check are
+/// different: they live in an associated `const` on a (possibly generic) `impl`
+/// block, and Rust only evaluates such a const once it is actually referenced for a
+/// concrete type. For a non-generic struct this happens unconditionally, but for a
+/// generic struct the check is only forced once `field_count`, `as_slice`, or
+/// `as_mut_slice` is called (directly or transitively) for some concrete type
+/// parameter; an unused generic struct will not have these checks run at all.
 ///
 /// ## Examples
 ///
@@ -73,6 +113,7 @@ use syn::{parse_macro_input, ItemStruct};
 ///
 /// ```compile_fail
 /// #[ensure_uniform_type::ensure_uniform_type]
+/// #[repr(C)]
 /// pub struct Example<T>
 /// {
 ///     /// First field
@@ -83,67 +124,539 @@ use syn::{parse_macro_input, ItemStruct};
 /// }
 /// ```
 ///
-/// The above would fail with the error:
-///
-/// ```plain
-/// error: Struct DifferentialDriveState has fields of different types. Expected uniform use of T, found u32 in field lol.
-/// --> src/differential_drive.rs:16:1
-/// |
-/// 16 | / /// A state of a differential drive robot, or differential wheeled robot.
-/// 18 | | #[ensure_uniform_type]
-/// 19 | | pub struct Example<T>
-/// ...  |
-/// 37 | |     offending: u32,
-/// 38 | | }
-/// | |_^
-/// ```
-///
 /// By contrast, the following would compile without an error:
 ///
 /// ```
 /// #[ensure_uniform_type::ensure_uniform_type]
+/// #[repr(C)]
 /// pub struct Example<T>
 /// {
 ///     x: T,
 ///     not_offending: T,
 /// }
 /// ```
+///
+/// The macro also adds `as_slice`, `as_mut_slice`, and `field_count` methods so the
+/// struct can be read as a contiguous slice of its uniform field type:
+///
+/// ```
+/// #[ensure_uniform_type::ensure_uniform_type]
+/// #[repr(C)]
+/// pub struct Example2 {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// let example = Example2 { x: 1.0, y: 2.0 };
+/// assert_eq!(Example2::field_count(), 2);
+/// assert_eq!(example.as_slice(), &[1.0, 2.0]);
+/// ```
+///
+/// By default, the struct must declare `#[repr(C)]` or `#[repr(transparent)]`, since
+/// otherwise the slice mapping above is not guaranteed to be sound. Pass
+/// `#[ensure_uniform_type(lenient)]` to only check field-type uniformity:
+///
+/// ```
+/// #[ensure_uniform_type::ensure_uniform_type(lenient)]
+/// pub struct Example3<T> {
+///     x: T,
+///     y: T,
+/// }
+/// ```
+///
+/// Tuple structs, unit structs, and `enum`s (checked per variant) are all supported:
+///
+/// ```
+/// #[ensure_uniform_type::ensure_uniform_type(lenient)]
+/// pub struct TupleExample<T>(T, T, T);
+///
+/// #[ensure_uniform_type::ensure_uniform_type(lenient)]
+/// pub struct UnitExample;
+///
+/// #[ensure_uniform_type::ensure_uniform_type(lenient)]
+/// pub enum EnumExample {
+///     A(f32, f32),
+///     B { x: u8, y: u8 },
+///     C,
+/// }
+/// ```
+///
+/// A variant whose own fields are not uniform still fails to compile, even though
+/// other variants may use a different, internally-uniform field type:
+///
+/// ```compile_fail
+/// #[ensure_uniform_type::ensure_uniform_type(lenient)]
+/// pub enum MismatchedVariant {
+///     Good(f32, f32),
+///     Bad(f32, u32),
+/// }
+/// ```
+///
+/// Enums never get `as_slice`/`as_mut_slice`/`field_count` accessors, since there is no
+/// single field layout to map the whole enum onto, so requesting a specific `repr` has
+/// nothing to enforce and is rejected:
+///
+/// ```compile_fail
+/// #[ensure_uniform_type::ensure_uniform_type(repr = "C")]
+/// pub enum ReprOnEnum {
+///     A(f32, f32),
+///     B(f32, f32),
+/// }
+/// ```
+///
+/// A field marked `#[uniform(skip)]`, or one whose type is `PhantomData<..>`, is
+/// excluded from both the uniformity check and the slice mapping:
+///
+/// ```
+/// use core::marker::PhantomData;
+///
+/// #[ensure_uniform_type::ensure_uniform_type]
+/// #[repr(C)]
+/// pub struct SkipExample<T> {
+///     x: T,
+///     y: T,
+///     #[uniform(skip)]
+///     label: (),
+///     tag: PhantomData<T>,
+/// }
+///
+/// assert_eq!(SkipExample::<f32>::field_count(), 2);
+/// ```
+///
+/// Skipping a field that is not actually zero-sized fails to compile once `field_count`,
+/// `as_slice`, or `as_mut_slice` is referenced for a concrete type, since that is what
+/// forces the backing `const` (and the assertion inside it) to be evaluated; see the
+/// "Note" section above:
+///
+/// ```compile_fail
+/// #[ensure_uniform_type::ensure_uniform_type]
+/// #[repr(C)]
+/// pub struct BadSkipExample {
+///     x: f32,
+///     y: f32,
+///     #[uniform(skip)]
+///     not_zero_sized: u64,
+/// }
+///
+/// let _ = BadSkipExample::field_count();
+/// ```
+///
+/// A `#[uniform(..)]` attribute with anything other than `skip` inside it is rejected
+/// with a clear error, rather than being left in place to fail later as a confusing
+/// "cannot find attribute `uniform`" error from rustc:
+///
+/// ```compile_fail
+/// #[ensure_uniform_type::ensure_uniform_type]
+/// #[repr(C)]
+/// pub struct TypoedSkipExample {
+///     x: f32,
+///     y: f32,
+///     #[uniform(Skip)]
+///     label: (),
+/// }
+/// ```
 #[proc_macro_attribute]
-pub fn ensure_uniform_type(_attr: TokenStream, item: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(item as ItemStruct);
+pub fn ensure_uniform_type(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let repr_requirement = parse_macro_input!(attr as ReprRequirement);
+    let input = parse_macro_input!(item as Item);
+
+    match input {
+        Item::Struct(item_struct) => ensure_uniform_struct(item_struct, repr_requirement),
+        Item::Enum(item_enum) => ensure_uniform_enum(item_enum, repr_requirement),
+        other => syn::Error::new_spanned(
+            &other,
+            "#[ensure_uniform_type] can only be applied to structs and enums",
+        )
+        .to_compile_error()
+        .into(),
+    }
+}
+
+/// Which specific `repr` was requested via `#[ensure_uniform_type(repr = "...")]`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum RequiredRepr {
+    C,
+    Transparent,
+}
+
+impl RequiredRepr {
+    fn ident(self) -> &'static str {
+        match self {
+            RequiredRepr::C => "C",
+            RequiredRepr::Transparent => "transparent",
+        }
+    }
+}
+
+/// Whether `#[ensure_uniform_type]` requires a layout-defining `repr` for a sound
+/// slice mapping, or only checks field-type uniformity.
+#[derive(Default)]
+enum ReprRequirement {
+    /// Require `#[repr(C)]` or `#[repr(transparent)]` (the default, when no specific
+    /// `repr` was requested).
+    #[default]
+    Required,
+    /// Require exactly the requested `repr`, e.g. `repr = "C"` accepts only
+    /// `#[repr(C)]`, not `#[repr(transparent)]`.
+    RequiredExact(RequiredRepr),
+    /// Skip the `repr` requirement; only check field-type uniformity.
+    Lenient,
+}
+
+impl syn::parse::Parse for ReprRequirement {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.is_empty() {
+            return Ok(ReprRequirement::default());
+        }
+
+        let ident: syn::Ident = input.parse()?;
+        if ident == "lenient" {
+            return Ok(ReprRequirement::Lenient);
+        }
+        if ident == "repr" {
+            input.parse::<syn::Token![=]>()?;
+            let value: syn::LitStr = input.parse()?;
+            return match value.value().as_str() {
+                "C" => Ok(ReprRequirement::RequiredExact(RequiredRepr::C)),
+                "transparent" => Ok(ReprRequirement::RequiredExact(RequiredRepr::Transparent)),
+                other => Err(syn::Error::new(
+                    value.span(),
+                    format!("unsupported repr \"{other}\", expected \"C\" or \"transparent\""),
+                )),
+            };
+        }
+
+        Err(syn::Error::new(
+            ident.span(),
+            "expected `lenient` or `repr = \"C\"`",
+        ))
+    }
+}
+
+/// Returns `true` if `attrs` contains a `#[repr(#ident)]`, e.g. `#[repr(C)]` for
+/// `ident == "C"`.
+fn has_repr(attrs: &[Attribute], ident: &str) -> bool {
+    attrs.iter().any(|attr| {
+        if !attr.path().is_ident("repr") {
+            return false;
+        }
+        let mut found = false;
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(ident) {
+                found = true;
+            }
+            Ok(())
+        });
+        found
+    })
+}
+
+/// Returns `true` if `attrs` satisfy `repr_requirement`.
+fn satisfies_repr_requirement(attrs: &[Attribute], repr_requirement: &ReprRequirement) -> bool {
+    match repr_requirement {
+        ReprRequirement::Required => has_repr(attrs, "C") || has_repr(attrs, "transparent"),
+        ReprRequirement::RequiredExact(required) => has_repr(attrs, required.ident()),
+        ReprRequirement::Lenient => true,
+    }
+}
+
+/// Parses a `#[uniform(..)]` attribute, returning `None` if `attr` is not a `#[uniform(..)]`
+/// attribute at all (so it is left untouched, for some other macro or tool to interpret),
+/// and `Some(Err(..))` if it is one but its content isn't the recognized `skip` marker, so
+/// the bogus attribute is reported with a clear error instead of silently leaking through
+/// to rustc as a confusing "cannot find attribute `uniform`" error.
+fn parse_uniform_attr(attr: &Attribute) -> Option<syn::Result<()>> {
+    if !attr.path().is_ident("uniform") {
+        return None;
+    }
+    Some(attr.parse_args::<syn::Ident>().and_then(|ident| {
+        if ident == "skip" {
+            Ok(())
+        } else {
+            Err(syn::Error::new_spanned(
+                &ident,
+                format!("unsupported `#[uniform({ident})]`, expected `#[uniform(skip)]`"),
+            ))
+        }
+    }))
+}
+
+/// Returns `true` if `attr` is `#[uniform(skip)]`.
+fn is_skip_attr(attr: &Attribute) -> bool {
+    matches!(parse_uniform_attr(attr), Some(Ok(())))
+}
+
+/// Returns `true` if `ty` is (a possibly-qualified) `PhantomData<..>`, detected
+/// structurally by its last path segment rather than by a fully-qualified match, so
+/// `std::marker::PhantomData`, `core::marker::PhantomData`, and a plain `PhantomData`
+/// import are all recognized.
+fn is_phantom_data(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "PhantomData"),
+        _ => false,
+    }
+}
+
+/// Splits a [`Fields`] value into the field types that participate in the uniformity
+/// check and slice mapping, and the field types that are excluded from it. A field is
+/// excluded when it is marked `#[uniform(skip)]` or when its type is `PhantomData<..>`.
+/// The `#[uniform(skip)]` attribute itself is stripped from the fields, since it is not
+/// a real attribute that rustc (or any helper-attribute registration) knows about.
+///
+/// Returns an error instead if a field carries a `#[uniform(..)]` attribute whose content
+/// isn't `skip` (e.g. a typo like `#[uniform(Skip)]`), rather than leaving it in place to
+/// fail later as a confusing "cannot find attribute `uniform`" error from rustc.
+fn classify_and_strip_fields(fields: &mut Fields) -> syn::Result<(Vec<Type>, Vec<Type>)> {
+    let mut checked = Vec::new();
+    let mut skipped = Vec::new();
+    let mut error: Option<syn::Error> = None;
+
+    let mut classify_one = |field: &mut syn::Field| {
+        let mut explicit_skip = false;
+        field.attrs.retain(|attr| match parse_uniform_attr(attr) {
+            None => true,
+            Some(Ok(())) => {
+                explicit_skip = true;
+                false
+            }
+            Some(Err(err)) => {
+                match &mut error {
+                    Some(existing) => existing.combine(err),
+                    None => error = Some(err),
+                }
+                false
+            }
+        });
+
+        if explicit_skip || is_phantom_data(&field.ty) {
+            skipped.push(field.ty.clone());
+        } else {
+            checked.push(field.ty.clone());
+        }
+    };
+
+    match fields {
+        Fields::Named(fields) => fields.named.iter_mut().for_each(&mut classify_one),
+        Fields::Unnamed(fields) => fields.unnamed.iter_mut().for_each(&mut classify_one),
+        Fields::Unit => {}
+    }
+
+    match error {
+        Some(err) => Err(err),
+        None => Ok((checked, skipped)),
+    }
+}
+
+/// Builds one compiler-checked assertion per adjacent pair of field types. A zero- or
+/// one-field list is trivially uniform and yields no assertions.
+fn pairwise_assertions(types: &[Type]) -> Vec<proc_macro2::TokenStream> {
+    types
+        .windows(2)
+        .map(|pair| {
+            let ty_a = &pair[0];
+            let ty_b = &pair[1];
+            quote_spanned! {ty_b.span()=>
+                __ensure_uniform_type_assert_same(
+                    core::marker::PhantomData::<#ty_a>,
+                    core::marker::PhantomData::<#ty_b>,
+                );
+            }
+        })
+        .collect()
+}
+
+/// Builds one zero-size assertion per skipped field type, so excluding a field from the
+/// uniformity check can never silently break the slice-mapping invariant by hiding a
+/// field that actually occupies space. For a struct, these assertions are embedded in an
+/// associated `const` (see [`ensure_uniform_struct`]), so for a generic struct they are
+/// only actually evaluated once that `const` is forced by a concrete-type use of
+/// `field_count`, `as_slice`, or `as_mut_slice`. For an enum (see [`ensure_uniform_enum`]),
+/// they are emitted into the body of the hidden check function, which is never called,
+/// so they are not evaluated.
+fn zero_sized_assertions(types: &[Type]) -> Vec<proc_macro2::TokenStream> {
+    types
+        .iter()
+        .map(|ty| {
+            quote_spanned! {ty.span()=>
+                assert!(core::mem::size_of::<#ty>() == 0);
+            }
+        })
+        .collect()
+}
+
+fn ensure_uniform_struct(mut input: ItemStruct, repr_requirement: ReprRequirement) -> TokenStream {
+    if !satisfies_repr_requirement(&input.attrs, &repr_requirement) {
+        let expected = match &repr_requirement {
+            ReprRequirement::Required => "#[repr(C)] or #[repr(transparent)]".to_string(),
+            ReprRequirement::RequiredExact(required) => format!("#[repr({})]", required.ident()),
+            ReprRequirement::Lenient => unreachable!("lenient mode accepts any repr"),
+        };
+        return syn::Error::new_spanned(
+            &input,
+            format!(
+                "#[ensure_uniform_type] requires {expected} for a sound slice mapping; add it, \
+                 or use #[ensure_uniform_type(lenient)] to only check field-type uniformity",
+            ),
+        )
+        .to_compile_error()
+        .into();
+    }
 
     let struct_name = &input.ident;
-    let fields = if let syn::Fields::Named(fields) = &input.fields {
-        &fields.named
-    } else {
-        unimplemented!("Only named fields are supported")
+    let (checked, skipped) = match classify_and_strip_fields(&mut input.fields) {
+        Ok(fields) => fields,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let assertions = pairwise_assertions(&checked);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // Compare every adjacent pair of field types by letting the compiler unify them
+    // against a single generic parameter. If two field types differ, the call below
+    // fails to type-check and rustc reports the mismatch at the offending field.
+    let check = quote! {
+        #[doc(hidden)]
+        #[allow(non_snake_case, dead_code)]
+        const _: () = {
+            fn __ensure_uniform_type_assert_same<T>(
+                _: core::marker::PhantomData<T>,
+                _: core::marker::PhantomData<T>,
+            ) {
+            }
+
+            fn __ensure_uniform_type_check #impl_generics () #where_clause {
+                #(#assertions)*
+            }
+        };
+    };
+
+    let zero_sized_checks = zero_sized_assertions(&skipped);
+
+    // A struct with no checked fields has no common field type to map a slice onto,
+    // so it only gets a `field_count` of zero.
+    let accessors = match checked.first() {
+        Some(common_ty) => {
+            let field_count = checked.len();
+            quote! {
+                impl #impl_generics #struct_name #ty_generics #where_clause {
+                    #[doc(hidden)]
+                    const __ENSURE_UNIFORM_TYPE_SIZE_CHECK: () = {
+                        #(#zero_sized_checks)*
+                        assert!(
+                            core::mem::size_of::<Self>()
+                                == #field_count * core::mem::size_of::<#common_ty>()
+                        );
+                        assert!(core::mem::align_of::<Self>() == core::mem::align_of::<#common_ty>());
+                    };
+
+                    /// Returns the number of fields in this uniform struct.
+                    pub const fn field_count() -> usize {
+                        Self::__ENSURE_UNIFORM_TYPE_SIZE_CHECK;
+                        #field_count
+                    }
+
+                    /// Reinterprets this struct as a slice of its uniform field type.
+                    pub fn as_slice(&self) -> &[#common_ty] {
+                        Self::__ENSURE_UNIFORM_TYPE_SIZE_CHECK;
+                        unsafe {
+                            core::slice::from_raw_parts(self as *const Self as *const #common_ty, #field_count)
+                        }
+                    }
+
+                    /// Reinterprets this struct as a mutable slice of its uniform field type.
+                    pub fn as_mut_slice(&mut self) -> &mut [#common_ty] {
+                        Self::__ENSURE_UNIFORM_TYPE_SIZE_CHECK;
+                        unsafe {
+                            core::slice::from_raw_parts_mut(self as *mut Self as *mut #common_ty, #field_count)
+                        }
+                    }
+                }
+            }
+        }
+        None => quote! {
+            impl #impl_generics #struct_name #ty_generics #where_clause {
+                #[doc(hidden)]
+                const __ENSURE_UNIFORM_TYPE_SIZE_CHECK: () = {
+                    #(#zero_sized_checks)*
+                };
+
+                /// Returns the number of fields in this uniform struct.
+                pub const fn field_count() -> usize {
+                    Self::__ENSURE_UNIFORM_TYPE_SIZE_CHECK;
+                    0
+                }
+            }
+        },
     };
 
-    // Assume the first field type is the required uniform size type
-    let first_field_type = &fields.first().unwrap().ty;
-
-    // HACK: We cannot compare syn::Type instances directly, so we instead compare them by name.
-    let first_field_type = quote!(#first_field_type).to_string();
-
-    for field in fields {
-        let field_name = field.ident.as_ref().expect("expected named field");
-        let field_type = &field.ty;
-        let field_type = quote!(#field_type).to_string();
-
-        if first_field_type != field_type {
-            let error_message = format!(
-                "Struct {} has fields of different types. Expected uniform use of {}, found {} in field {}.",
-                struct_name,
-                first_field_type,
-                field_type,
-                field_name
-            );
-            return syn::Error::new_spanned(input, error_message)
-                .to_compile_error()
-                .into();
+    TokenStream::from(quote! {
+        #input
+        #check
+        #accessors
+    })
+}
+
+/// Checks each variant of an enum independently: the fields of a given variant must
+/// be uniform among themselves, but different variants may use different field types.
+///
+/// Unlike structs, enums never get `as_slice`/`as_mut_slice`/`field_count` accessors,
+/// since there is no single field layout to reinterpret the whole enum as a slice of.
+/// An explicitly requested `repr = "C"`/`repr = "transparent"` therefore has nothing to
+/// enforce and is rejected with a compile error; the bare, argument-less attribute is
+/// still accepted for backwards compatibility and behaves like `lenient`.
+fn ensure_uniform_enum(mut input: ItemEnum, repr_requirement: ReprRequirement) -> TokenStream {
+    if let ReprRequirement::RequiredExact(required) = &repr_requirement {
+        return syn::Error::new_spanned(
+            &input,
+            format!(
+                "#[ensure_uniform_type(repr = \"{}\")] has no effect on enums, since enums do \
+                 not get an `as_slice`/`as_mut_slice` mapping; remove the `repr` argument or use \
+                 #[ensure_uniform_type(lenient)]",
+                required.ident(),
+            ),
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let (impl_generics, _, where_clause) = input.generics.split_for_impl();
+
+    let mut assertions = Vec::new();
+    let mut error: Option<syn::Error> = None;
+    for variant in &mut input.variants {
+        match classify_and_strip_fields(&mut variant.fields) {
+            Ok((checked, skipped)) => {
+                assertions.extend(pairwise_assertions(&checked));
+                assertions.extend(zero_sized_assertions(&skipped));
+            }
+            Err(err) => match &mut error {
+                Some(existing) => existing.combine(err),
+                None => error = Some(err),
+            },
         }
     }
+    if let Some(err) = error {
+        return err.to_compile_error().into();
+    }
+
     TokenStream::from(quote! {
         #input
+
+        #[doc(hidden)]
+        #[allow(non_snake_case, dead_code)]
+        const _: () = {
+            fn __ensure_uniform_type_assert_same<T>(
+                _: core::marker::PhantomData<T>,
+                _: core::marker::PhantomData<T>,
+            ) {
+            }
+
+            fn __ensure_uniform_type_check #impl_generics () #where_clause {
+                #(#assertions)*
+            }
+        };
     })
 }